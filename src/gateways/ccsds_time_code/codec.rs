@@ -0,0 +1,200 @@
+use attestify_kernel::values::datetime::utc_timestamp::UTCTimestamp;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+/// Unix seconds of the CCSDS agency epoch, 1958-01-01T00:00:00Z, which both the CUC and CDS time
+/// codes in this module use by default.
+pub const CCSDS_AGENCY_EPOCH_UNIX_SECONDS: i64 = -378_691_200;
+
+/// A table of UTC-to-TAI leap second offsets, injected rather than hardcoded because CCSDS CUC
+/// time is TAI-based while [`UTCTimestamp`] is UTC-based, and the offset has changed over time
+/// (37s as of this writing) and may change again.
+///
+/// A bare timestamp without the table in effect at the time it denotes cannot be unambiguously
+/// round-tripped across a leap second boundary; callers are responsible for keeping the table
+/// current.
+#[derive(Clone)]
+pub struct LeapSecondTable {
+	/// `(utc_seconds_since_unix_epoch, tai_minus_utc_seconds)`, sorted ascending by the first
+	/// element. The offset in effect for a given UTC instant is the last entry whose
+	/// `utc_seconds_since_unix_epoch` is `<=` that instant.
+	entries: Vec<(i64, i64)>,
+}
+
+impl LeapSecondTable {
+	/// Build a table from `(utc_seconds_since_unix_epoch, tai_minus_utc_seconds)` pairs. The
+	/// caller must supply them in ascending order by the first element.
+	pub fn new(entries: Vec<(i64, i64)>) -> Self {
+		Self { entries }
+	}
+
+	/// A table with a single, constant TAI-UTC offset — e.g. the current 37s offset — in effect
+	/// for all time.
+	pub fn constant(tai_minus_utc_seconds: i64) -> Self {
+		Self { entries: vec![(i64::MIN, tai_minus_utc_seconds)] }
+	}
+
+	fn offset_at(&self, utc_seconds_since_unix_epoch: i64) -> Result<i64, Error> {
+		self.entries.iter()
+			.rev()
+			.find(|(effective_from, _)| *effective_from <= utc_seconds_since_unix_epoch)
+			.map(|(_, offset)| *offset)
+			.ok_or_else(|| Error::for_system(Kind::ProcessingFailure,
+				"No leap second table entry covers this timestamp".to_string()))
+	}
+}
+
+/// Encodes and decodes [`UTCTimestamp`] values as CCSDS time codes (CCSDS 301.0-B-4), for
+/// spacecraft/telemetry consumers.
+pub struct CcsdsTimeCodec {
+	leap_seconds: LeapSecondTable,
+	agency_epoch_unix_seconds: i64,
+}
+
+impl CcsdsTimeCodec {
+	/// Build a codec using the CCSDS agency epoch (1958-01-01) and the given leap second table.
+	pub fn new(leap_seconds: LeapSecondTable) -> Self {
+		Self { leap_seconds, agency_epoch_unix_seconds: CCSDS_AGENCY_EPOCH_UNIX_SECONDS }
+	}
+
+	/// Build a codec against a custom agency-defined epoch, expressed as Unix seconds.
+	pub fn with_agency_epoch(leap_seconds: LeapSecondTable, agency_epoch_unix_seconds: i64) -> Self {
+		Self { leap_seconds, agency_epoch_unix_seconds }
+	}
+
+	/// Encode `timestamp` as a CCSDS Unsegmented (CUC) time code: a P-field byte describing the
+	/// epoch and octet counts, followed by `coarse_octets` of TAI seconds since the agency epoch
+	/// and `fine_octets` of sub-second fraction in 1/256ths-per-octet increments.
+	pub fn to_cuc_bytes(&self, timestamp: &UTCTimestamp, coarse_octets: u8, fine_octets: u8) -> Result<Vec<u8>, Error> {
+		if !(1..=4).contains(&coarse_octets) || !(0..=3).contains(&fine_octets) {
+			return Err(Error::for_system(Kind::ProcessingFailure,
+				format!("CUC coarse_octets must be 1..=4 and fine_octets 0..=3, got {} and {}", coarse_octets, fine_octets)));
+		}
+
+		let utc_seconds = timestamp.as_sec() as i64;
+		let tai_seconds = utc_seconds + self.leap_seconds.offset_at(utc_seconds)?;
+		let coarse = tai_seconds - self.agency_epoch_unix_seconds;
+
+		if coarse < 0 || coarse >= (1i128 << (coarse_octets as u32 * 8)) as i64 {
+			return Err(Error::for_system(Kind::ProcessingFailure,
+				format!("TAI seconds since the agency epoch ({}) do not fit in {} coarse octets", coarse, coarse_octets)));
+		}
+
+		let fraction_of_second = (timestamp.as_nano() % 1_000_000_000) as f64 / 1_000_000_000.0;
+		let fine_field_max = (1u64 << (fine_octets as u32 * 8)) - 1;
+		// Rounding can push the scaled fraction up to the field width (e.g. 0.999_999_999s with
+		// fine_octets = 1 rounds to 256); clamp instead of letting it silently overflow into the
+		// zero byte that `to_be_bytes()[8-fine_octets..]` would otherwise take below.
+		let fine = ((fraction_of_second * (fine_field_max + 1) as f64).round() as u64).min(fine_field_max);
+
+		let mut bytes = Vec::with_capacity(1 + coarse_octets as usize + fine_octets as usize);
+		bytes.push(0b0_010_00_00 | ((coarse_octets - 1) << 2) | fine_octets);
+		bytes.extend_from_slice(&(coarse as u64).to_be_bytes()[8 - coarse_octets as usize..]);
+		if fine_octets > 0 {
+			bytes.extend_from_slice(&fine.to_be_bytes()[8 - fine_octets as usize..]);
+		}
+
+		Ok(bytes)
+	}
+
+	/// Decode a CUC time code produced by [`to_cuc_bytes`](Self::to_cuc_bytes).
+	pub fn from_cuc_bytes(&self, bytes: &[u8]) -> Result<UTCTimestamp, Error> {
+		let p_field = *bytes.first()
+			.ok_or_else(|| Error::for_system(Kind::ProcessingFailure, "CUC time code is empty".to_string()))?;
+
+		let coarse_octets = (((p_field >> 2) & 0b11) + 1) as usize;
+		let fine_octets = (p_field & 0b11) as usize;
+
+		if bytes.len() != 1 + coarse_octets + fine_octets {
+			return Err(Error::for_system(Kind::ProcessingFailure,
+				format!("CUC time code declares {} coarse + {} fine octets but has {} body bytes",
+						coarse_octets, fine_octets, bytes.len() - 1)));
+		}
+
+		let mut coarse_buf = [0u8; 8];
+		coarse_buf[8 - coarse_octets..].copy_from_slice(&bytes[1..1 + coarse_octets]);
+		let coarse = u64::from_be_bytes(coarse_buf) as i64;
+
+		let mut fine = 0u64;
+		if fine_octets > 0 {
+			let mut fine_buf = [0u8; 8];
+			fine_buf[8 - fine_octets..].copy_from_slice(&bytes[1 + coarse_octets..]);
+			fine = u64::from_be_bytes(fine_buf);
+		}
+
+		let tai_seconds = self.agency_epoch_unix_seconds + coarse;
+		let fraction_of_second = fine as f64 / (1u64 << (fine_octets as u32 * 8)) as f64;
+
+		// The table is keyed by UTC seconds: an entry effective from UTC second `u` with offset
+		// `o` takes effect at TAI second `u + o`, so compare the TAI seconds against that to pick
+		// the correct table entry.
+		let offset = self.leap_seconds.entries.iter()
+			.rev()
+			.find(|(effective_from, offset)| effective_from.saturating_add(*offset) <= tai_seconds)
+			.map(|(_, offset)| *offset)
+			.ok_or_else(|| Error::for_system(Kind::ProcessingFailure,
+				"No leap second table entry covers this timestamp".to_string()))?;
+		let utc_seconds = tai_seconds - offset;
+		let nanos_within_second = (fraction_of_second * 1_000_000_000.0).round() as u128;
+
+		UTCTimestamp::builder()
+			.use_ns((utc_seconds as u128) * 1_000_000_000 + nanos_within_second)
+			.build()
+			.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+											format!("Failed to build the UTCTimestamp from the CUC time code: {}", e)))
+	}
+
+	/// Encode `timestamp` as a CCSDS Day-Segmented (CDS) time code: 16-bit days since
+	/// 1958-01-01, a 32-bit milliseconds-of-day field, and (if `include_submillis`) a 16-bit
+	/// microseconds-of-millisecond field.
+	pub fn to_cds_bytes(&self, timestamp: &UTCTimestamp, include_submillis: bool) -> Result<Vec<u8>, Error> {
+		let utc_seconds = timestamp.as_sec() as i64;
+		let seconds_since_epoch = utc_seconds - CCSDS_AGENCY_EPOCH_UNIX_SECONDS;
+
+		let days = seconds_since_epoch.div_euclid(86_400);
+		let seconds_of_day = seconds_since_epoch.rem_euclid(86_400);
+
+		if !(0..=u16::MAX as i64).contains(&days) {
+			return Err(Error::for_system(Kind::ProcessingFailure,
+				format!("Days since the CDS epoch ({}) do not fit in 16 bits", days)));
+		}
+
+		let nanos_within_second = (timestamp.as_nano() % 1_000_000_000) as i64;
+		let millis_of_day = seconds_of_day * 1000 + nanos_within_second / 1_000_000;
+		let micros_of_milli = (nanos_within_second % 1_000_000) / 1000;
+
+		let mut bytes = Vec::with_capacity(if include_submillis { 8 } else { 6 });
+		bytes.extend_from_slice(&(days as u16).to_be_bytes());
+		bytes.extend_from_slice(&(millis_of_day as u32).to_be_bytes());
+		if include_submillis {
+			bytes.extend_from_slice(&(micros_of_milli as u16).to_be_bytes());
+		}
+
+		Ok(bytes)
+	}
+
+	/// Decode a CDS time code produced by [`to_cds_bytes`](Self::to_cds_bytes).
+	pub fn from_cds_bytes(&self, bytes: &[u8]) -> Result<UTCTimestamp, Error> {
+		if bytes.len() != 6 && bytes.len() != 8 {
+			return Err(Error::for_system(Kind::ProcessingFailure,
+				format!("CDS time code must be 6 or 8 bytes, got {}", bytes.len())));
+		}
+
+		let days = u16::from_be_bytes([bytes[0], bytes[1]]) as i64;
+		let millis_of_day = u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]) as i64;
+		let micros_of_milli = if bytes.len() == 8 {
+			u16::from_be_bytes([bytes[6], bytes[7]]) as i64
+		} else {
+			0
+		};
+
+		let utc_seconds = CCSDS_AGENCY_EPOCH_UNIX_SECONDS + days * 86_400 + millis_of_day / 1000;
+		let nanos_within_second = (millis_of_day % 1000) * 1_000_000 + micros_of_milli * 1000;
+
+		UTCTimestamp::builder()
+			.use_ns((utc_seconds as u128) * 1_000_000_000 + nanos_within_second as u128)
+			.build()
+			.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+											format!("Failed to build the UTCTimestamp from the CDS time code: {}", e)))
+	}
+}
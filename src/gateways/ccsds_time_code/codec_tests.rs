@@ -0,0 +1,93 @@
+use crate::gateways::ccsds_time_code::codec::{CcsdsTimeCodec, LeapSecondTable};
+use crate::gateways::utc_timestamp::controllable::ControllableUTCTimeStampGW;
+
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_test_framework::is_ok;
+
+/// # Test: `cuc_round_trips_through_to_and_from_bytes`
+///
+/// ## Purpose
+/// - Verify a timestamp encoded as a CUC time code decodes back to the same instant, to within
+///   the precision afforded by the chosen fine-octet count.
+#[test]
+fn cuc_round_trips_through_to_and_from_bytes() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_500_000_000);
+	let timestamp = is_ok!(gateway.now());
+
+	let codec = CcsdsTimeCodec::new(LeapSecondTable::constant(37));
+	let bytes = codec.to_cuc_bytes(&timestamp, 4, 2).expect("encoding should succeed");
+	let decoded = codec.from_cuc_bytes(&bytes).expect("decoding should succeed");
+
+	assert_eq!(decoded.as_sec(), timestamp.as_sec());
+}
+
+/// # Test: `cuc_fine_field_clamps_instead_of_overflowing_near_a_second_boundary`
+///
+/// ## Purpose
+/// - Verify that a sub-second fraction close enough to 1.0 to round up to the full fine-field
+///   width (e.g. `999_999_999` ns) is clamped to the field's maximum value instead of wrapping
+///   to `0`, for both a narrow (1-octet) and wide (3-octet) fine field.
+#[test]
+fn cuc_fine_field_clamps_instead_of_overflowing_near_a_second_boundary() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_999_999_999);
+	let timestamp = is_ok!(gateway.now());
+
+	let codec = CcsdsTimeCodec::new(LeapSecondTable::constant(37));
+
+	let bytes_1_octet = codec.to_cuc_bytes(&timestamp, 4, 1).expect("encoding should succeed");
+	assert_eq!(bytes_1_octet[5], 0xFF, "1-octet fine field should clamp to its max, not wrap to 0");
+
+	let bytes_3_octet = codec.to_cuc_bytes(&timestamp, 4, 3).expect("encoding should succeed");
+	assert_eq!(&bytes_3_octet[5..8], &[0xFF, 0xFF, 0xFF], "3-octet fine field should clamp to its max, not wrap to 0");
+
+	let decoded = codec.from_cuc_bytes(&bytes_3_octet).expect("decoding should succeed");
+	assert_eq!(decoded.as_sec(), timestamp.as_sec());
+}
+
+/// # Test: `cuc_rejects_out_of_range_octet_counts`
+///
+/// ## Purpose
+/// - Verify `to_cuc_bytes` returns an `Err` rather than panicking when asked for an
+///   out-of-range octet count.
+#[test]
+fn cuc_rejects_out_of_range_octet_counts() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_000_000_000);
+	let timestamp = is_ok!(gateway.now());
+
+	let codec = CcsdsTimeCodec::new(LeapSecondTable::constant(37));
+
+	assert!(codec.to_cuc_bytes(&timestamp, 0, 0).is_err());
+	assert!(codec.to_cuc_bytes(&timestamp, 5, 0).is_err());
+	assert!(codec.to_cuc_bytes(&timestamp, 4, 4).is_err());
+}
+
+/// # Test: `cds_round_trips_through_to_and_from_bytes`
+///
+/// ## Purpose
+/// - Verify a timestamp encoded as a CDS time code (with the optional sub-millisecond field)
+///   decodes back to the same instant.
+#[test]
+fn cds_round_trips_through_to_and_from_bytes() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_123_456_000);
+	let timestamp = is_ok!(gateway.now());
+
+	let codec = CcsdsTimeCodec::new(LeapSecondTable::constant(37));
+	let bytes = codec.to_cds_bytes(&timestamp, true).expect("encoding should succeed");
+	let decoded = codec.from_cds_bytes(&bytes).expect("decoding should succeed");
+
+	assert_eq!(decoded.as_sec(), timestamp.as_sec());
+	assert_eq!(decoded.as_milli(), timestamp.as_milli());
+}
+
+/// # Test: `cds_rejects_malformed_byte_lengths`
+///
+/// ## Purpose
+/// - Verify `from_cds_bytes` returns an `Err` rather than panicking or indexing out of bounds
+///   for a buffer of the wrong length.
+#[test]
+fn cds_rejects_malformed_byte_lengths() {
+	let codec = CcsdsTimeCodec::new(LeapSecondTable::constant(37));
+
+	assert!(codec.from_cds_bytes(&[0u8; 5]).is_err());
+	assert!(codec.from_cds_bytes(&[0u8; 7]).is_err());
+}
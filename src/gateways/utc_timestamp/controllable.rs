@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use attestify_kernel::values::datetime::utc_timestamp::UTCTimestamp;
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+/// A single outcome a [`ControllableUTCTimeStampGW`] can hand back from one `now()` call.
+#[derive(Clone)]
+pub enum ControlledOutcome {
+	/// Build a real `UTCTimestamp` from this nanosecond count.
+	Ns(u128),
+	/// Fail as if the underlying clock source itself failed, e.g. `SystemTime::duration_since`
+	/// returning an error because the clock reads before `UNIX_EPOCH`.
+	GatewayFailure(String),
+	/// Fail as if `UTCTimestamp::builder().build()` rejected the nanosecond count.
+	BuilderFailure(String),
+}
+
+enum Source {
+	/// Always returns the same outcome.
+	Fixed(ControlledOutcome),
+	/// Returns the next outcome from the queue, in order; errors once exhausted.
+	Queue(VecDeque<ControlledOutcome>),
+	/// Returns `start`, then `start + step`, then `start + 2*step`, ...
+	Counter { next: u128, step: u128 },
+}
+
+/// A [`UTCTimestampGateway`] driven entirely by test-provided data instead of the wall clock.
+///
+/// This exists so the currently-untestable failure branches of `SystemTimeUTCTimeStampGW` (the
+/// `duration_since(UNIX_EPOCH)` failure and the builder-failure path) can be exercised by any
+/// downstream code that only depends on the `UTCTimestampGateway` trait.
+pub struct ControllableUTCTimeStampGW {
+	source: Mutex<Source>,
+}
+
+impl ControllableUTCTimeStampGW {
+	/// Always return the given nanosecond count.
+	pub fn fixed_ns(ns: u128) -> Self {
+		Self { source: Mutex::new(Source::Fixed(ControlledOutcome::Ns(ns))) }
+	}
+
+	/// Always return the given outcome, e.g. a `GatewayFailure` or `BuilderFailure`.
+	pub fn fixed(outcome: ControlledOutcome) -> Self {
+		Self { source: Mutex::new(Source::Fixed(outcome)) }
+	}
+
+	/// Consume one outcome per `now()` call, in order. Once exhausted, `now()` returns a
+	/// `GatewayError`.
+	pub fn from_queue(outcomes: impl IntoIterator<Item = ControlledOutcome>) -> Self {
+		Self { source: Mutex::new(Source::Queue(outcomes.into_iter().collect())) }
+	}
+
+	/// Return a monotonically advancing nanosecond counter: `start`, `start + step`, ...
+	pub fn counter(start: u128, step: u128) -> Self {
+		Self { source: Mutex::new(Source::Counter { next: start, step }) }
+	}
+
+	fn build(ns: u128) -> Result<UTCTimestamp, Error> {
+		UTCTimestamp::builder()
+			.use_ns(ns)
+			.build()
+			.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+											format!("Failed to build the UTCTimestamp from the controlled value: {}", e)))
+	}
+
+	fn resolve(outcome: ControlledOutcome) -> Result<UTCTimestamp, Error> {
+		match outcome {
+			ControlledOutcome::Ns(ns) => Self::build(ns),
+			ControlledOutcome::GatewayFailure(message) => Err(Error::for_system(Kind::GatewayError, message)),
+			ControlledOutcome::BuilderFailure(message) => Err(Error::for_system(Kind::ProcessingFailure, message)),
+		}
+	}
+}
+
+impl UTCTimestampGateway for ControllableUTCTimeStampGW {
+	fn now(&self) -> Result<UTCTimestamp, Error> {
+		let mut source = self.source.lock()
+			.expect("ControllableUTCTimeStampGW mutex poisoned");
+
+		match &mut *source {
+			Source::Fixed(outcome) => Self::resolve(outcome.clone()),
+			Source::Queue(queue) => {
+				let outcome = queue.pop_front()
+					.ok_or_else(|| Error::for_system(Kind::GatewayError,
+						"ControllableUTCTimeStampGW queue exhausted".to_string()))?;
+				Self::resolve(outcome)
+			}
+			Source::Counter { next, step } => {
+				let ns = *next;
+				*next += *step;
+				Self::build(ns)
+			}
+		}
+	}
+}
@@ -0,0 +1,78 @@
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use crate::gateways::utc_timestamp::controllable::{ControllableUTCTimeStampGW, ControlledOutcome};
+
+use attestify_test_framework::is_ok;
+
+/// # Test: `fixed_ns_returns_the_same_value_every_call`
+///
+/// ## Purpose
+/// - Verify `ControllableUTCTimeStampGW::fixed_ns` always reports the same instant, regardless
+///   of how many times `now()` is called.
+#[test]
+fn fixed_ns_returns_the_same_value_every_call() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_000_000_000);
+
+	let first = is_ok!(gateway.now());
+	let second = is_ok!(gateway.now());
+
+	assert_eq!(first.as_nano(), 1_700_000_000_000_000_000);
+	assert_eq!(first.as_nano(), second.as_nano());
+}
+
+/// # Test: `gateway_failure_exercises_the_duration_since_failure_path`
+///
+/// ## Purpose
+/// - Verify a `ControlledOutcome::GatewayFailure` surfaces as an `Err`, simulating the
+///   `SystemTime::duration_since(UNIX_EPOCH)` failure that real wall-clock regressions can cause
+///   but that cannot otherwise be triggered deterministically in a unit test.
+#[test]
+fn gateway_failure_exercises_the_duration_since_failure_path() {
+	let gateway = ControllableUTCTimeStampGW::fixed(
+		ControlledOutcome::GatewayFailure("clock went backwards".to_string()));
+
+	assert!(gateway.now().is_err());
+}
+
+/// # Test: `builder_failure_exercises_the_build_failure_path`
+///
+/// ## Purpose
+/// - Verify a `ControlledOutcome::BuilderFailure` surfaces as an `Err`, simulating
+///   `UTCTimestamp::builder().build()` rejecting a nanosecond count.
+#[test]
+fn builder_failure_exercises_the_build_failure_path() {
+	let gateway = ControllableUTCTimeStampGW::fixed(
+		ControlledOutcome::BuilderFailure("nanosecond count out of range".to_string()));
+
+	assert!(gateway.now().is_err());
+}
+
+/// # Test: `queue_is_consumed_one_outcome_per_call_then_errors`
+///
+/// ## Purpose
+/// - Verify a pre-seeded queue yields its outcomes in order, one per `now()` call, and that
+///   calling `now()` after the queue is drained returns an `Err` instead of panicking.
+#[test]
+fn queue_is_consumed_one_outcome_per_call_then_errors() {
+	let gateway = ControllableUTCTimeStampGW::from_queue(vec![
+		ControlledOutcome::Ns(1_000_000_000),
+		ControlledOutcome::Ns(2_000_000_000),
+	]);
+
+	assert_eq!(is_ok!(gateway.now()).as_nano(), 1_000_000_000);
+	assert_eq!(is_ok!(gateway.now()).as_nano(), 2_000_000_000);
+	assert!(gateway.now().is_err());
+}
+
+/// # Test: `counter_advances_by_step_on_every_call`
+///
+/// ## Purpose
+/// - Verify the counter source starts at the configured value and advances by `step` on every
+///   subsequent `now()` call.
+#[test]
+fn counter_advances_by_step_on_every_call() {
+	let gateway = ControllableUTCTimeStampGW::counter(1_000, 500);
+
+	assert_eq!(is_ok!(gateway.now()).as_nano(), 1_000);
+	assert_eq!(is_ok!(gateway.now()).as_nano(), 1_500);
+	assert_eq!(is_ok!(gateway.now()).as_nano(), 2_000);
+}
@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use attestify_kernel::values::datetime::utc_timestamp::UTCTimestamp;
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+/// Wraps any [`UTCTimestampGateway`] and guarantees that the nanosecond values it returns never
+/// go backward, even if the wrapped clock does (e.g. an NTP correction or leap-second smear).
+///
+/// Each call returns `max(inner_now, last + 1ns)`, so by default the stream of timestamps is
+/// strictly increasing; call [`allow_repeats`](Self::allow_repeats) to relax that to merely
+/// non-decreasing. Wrapped-clock regressions larger than the configured threshold surface as an
+/// `Error` rather than being silently clamped.
+pub struct MonotonicUTCTimeStampGW<G: UTCTimestampGateway> {
+	inner: G,
+	last_ns: AtomicU64,
+	strict: bool,
+	max_backward_jump_ns: u64,
+}
+
+impl<G: UTCTimestampGateway> MonotonicUTCTimeStampGW<G> {
+	/// Wrap `inner`, requiring strictly increasing timestamps and tolerating any size of
+	/// backward jump from the wrapped clock.
+	pub fn new(inner: G) -> Self {
+		Self {
+			inner,
+			last_ns: AtomicU64::new(0),
+			strict: true,
+			max_backward_jump_ns: u64::MAX,
+		}
+	}
+
+	/// Allow the wrapped clock to repeat its last value instead of always advancing by at
+	/// least 1ns.
+	pub fn allow_repeats(mut self) -> Self {
+		self.strict = false;
+		self
+	}
+
+	/// Surface an `Error` instead of clamping when the wrapped clock regresses by more than
+	/// `max_backward_jump_ns` relative to the last value returned.
+	pub fn with_max_backward_jump_ns(mut self, max_backward_jump_ns: u64) -> Self {
+		self.max_backward_jump_ns = max_backward_jump_ns;
+		self
+	}
+}
+
+impl<G: UTCTimestampGateway> UTCTimestampGateway for MonotonicUTCTimeStampGW<G> {
+	fn now(&self) -> Result<UTCTimestamp, Error> {
+		let observed_ns = self.inner.now()?.as_nano() as u64;
+
+		// A plain load-compute-fetch_max would let two concurrent callers compute and publish
+		// the same emitted_ns, breaking the strictly-increasing guarantee. fetch_update makes
+		// the whole read-modify-write a single atomic compare-exchange loop instead.
+		let mut rejected_last_ns = None;
+		let update = self.last_ns.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last_ns| {
+			if observed_ns < last_ns && last_ns - observed_ns > self.max_backward_jump_ns {
+				rejected_last_ns = Some(last_ns);
+				return None;
+			}
+
+			let floor = if self.strict { last_ns.saturating_add(1) } else { last_ns };
+			Some(observed_ns.max(floor))
+		});
+
+		let emitted_ns = match update {
+			// fetch_update hands back the value it replaced; recompute the value it stored.
+			Ok(previous_ns) => {
+				let floor = if self.strict { previous_ns.saturating_add(1) } else { previous_ns };
+				observed_ns.max(floor)
+			}
+			Err(_) => {
+				let last_ns = rejected_last_ns.expect("fetch_update failure always records the rejecting last_ns");
+				return Err(Error::for_system(Kind::GatewayError,
+					format!("Wrapped clock regressed from {} ns to {} ns, exceeding the allowed {} ns backward jump",
+							last_ns, observed_ns, self.max_backward_jump_ns)));
+			}
+		};
+
+		UTCTimestamp::builder()
+			.use_ns(emitted_ns as u128)
+			.build()
+			.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+											format!("Failed to build the UTCTimestamp from the monotonic value: {}", e)))
+	}
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use crate::gateways::utc_timestamp::controllable::{ControllableUTCTimeStampGW, ControlledOutcome};
+use crate::gateways::utc_timestamp::monotonic::MonotonicUTCTimeStampGW;
+
+use attestify_test_framework::is_ok;
+
+/// # Test: `repeated_inner_value_still_advances_by_default`
+///
+/// ## Purpose
+/// - Verify that by default (strict mode), two equal readings from the wrapped clock still
+///   produce strictly increasing output, one nanosecond apart.
+#[test]
+fn repeated_inner_value_still_advances_by_default() {
+	let inner = ControllableUTCTimeStampGW::fixed_ns(1_000_000_000);
+	let gateway = MonotonicUTCTimeStampGW::new(inner);
+
+	let first = is_ok!(gateway.now()).as_nano();
+	let second = is_ok!(gateway.now()).as_nano();
+
+	assert!(second > first, "expected {} > {}", second, first);
+}
+
+/// # Test: `allow_repeats_permits_equal_consecutive_values`
+///
+/// ## Purpose
+/// - Verify that with `allow_repeats()`, a wrapped clock returning the same value twice is
+///   passed through unchanged rather than bumped forward.
+#[test]
+fn allow_repeats_permits_equal_consecutive_values() {
+	let inner = ControllableUTCTimeStampGW::fixed_ns(1_000_000_000);
+	let gateway = MonotonicUTCTimeStampGW::new(inner).allow_repeats();
+
+	let first = is_ok!(gateway.now()).as_nano();
+	let second = is_ok!(gateway.now()).as_nano();
+
+	assert_eq!(first, second);
+}
+
+/// # Test: `backward_jump_within_threshold_is_clamped_forward`
+///
+/// ## Purpose
+/// - Verify a small backward jump from the wrapped clock (within the configured threshold) is
+///   clamped forward to `last + 1ns` instead of erroring.
+#[test]
+fn backward_jump_within_threshold_is_clamped_forward() {
+	let inner = ControllableUTCTimeStampGW::from_queue(vec![
+		ControlledOutcome::Ns(2_000_000_000),
+		ControlledOutcome::Ns(1_000_000_000),
+	]);
+	let gateway = MonotonicUTCTimeStampGW::new(inner)
+		.with_max_backward_jump_ns(5_000_000_000);
+
+	let first = is_ok!(gateway.now()).as_nano();
+	let second = is_ok!(gateway.now()).as_nano();
+
+	assert_eq!(first, 2_000_000_000);
+	assert!(second > first, "expected the regression to be clamped forward, got {}", second);
+}
+
+/// # Test: `backward_jump_past_threshold_is_surfaced_as_an_error`
+///
+/// ## Purpose
+/// - Verify a backward jump from the wrapped clock larger than the configured threshold is
+///   surfaced as an `Err` rather than silently clamped.
+#[test]
+fn backward_jump_past_threshold_is_surfaced_as_an_error() {
+	let inner = ControllableUTCTimeStampGW::from_queue(vec![
+		ControlledOutcome::Ns(10_000_000_000),
+		ControlledOutcome::Ns(1_000_000_000),
+	]);
+	let gateway = MonotonicUTCTimeStampGW::new(inner)
+		.with_max_backward_jump_ns(1_000_000);
+
+	let _first = is_ok!(gateway.now());
+	assert!(gateway.now().is_err());
+}
+
+/// # Test: `concurrent_callers_never_observe_a_duplicate_timestamp_in_strict_mode`
+///
+/// ## Purpose
+/// - Verify that many threads hammering one shared `MonotonicUTCTimeStampGW` (all seeing the
+///   same wrapped-clock reading) never get back the same nanosecond value twice in strict mode.
+///   A `load` → compute → `fetch_max` implementation can let two threads race and both publish
+///   the same value; this guards against that regression.
+#[test]
+fn concurrent_callers_never_observe_a_duplicate_timestamp_in_strict_mode() {
+	const THREADS: usize = 16;
+	const CALLS_PER_THREAD: usize = 200;
+
+	let inner = ControllableUTCTimeStampGW::fixed_ns(1_000_000_000);
+	let gateway = Arc::new(MonotonicUTCTimeStampGW::new(inner));
+
+	let handles: Vec<_> = (0..THREADS)
+		.map(|_| {
+			let gateway = Arc::clone(&gateway);
+			thread::spawn(move || {
+				(0..CALLS_PER_THREAD)
+					.map(|_| is_ok!(gateway.now()).as_nano())
+					.collect::<Vec<_>>()
+			})
+		})
+		.collect();
+
+	let mut seen = HashSet::new();
+	for handle in handles {
+		for value in handle.join().expect("worker thread should not panic") {
+			assert!(seen.insert(value), "timestamp {} was returned more than once", value);
+		}
+	}
+
+	assert_eq!(seen.len(), THREADS * CALLS_PER_THREAD);
+}
@@ -0,0 +1,57 @@
+//! `no_std`-compatible wall-clock driver, for kernel/embedded builds that can't link `std`.
+//!
+//! Requires the `posix_clock_gettime` feature, which pulls in `libc` for the `clock_gettime`
+//! binding. `alloc` is still required, since `Error` carries an owned message.
+#![cfg(feature = "posix_clock_gettime")]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::ToString;
+use core::mem::MaybeUninit;
+
+use attestify_kernel::values::datetime::utc_timestamp::UTCTimestamp;
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+/// A [`UTCTimestampGateway`] that reads the wall clock via the POSIX `clock_gettime` syscall
+/// instead of `std::time::SystemTime`, so it is available in `no_std` kernel/embedded builds. On
+/// Linux this resolves through the vDSO, avoiding an actual syscall in the common case.
+#[derive(Clone, Default)]
+pub struct PosixClockGettimeUTCTimeStampGW;
+
+impl PosixClockGettimeUTCTimeStampGW {
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl UTCTimestampGateway for PosixClockGettimeUTCTimeStampGW {
+	fn now(&self) -> Result<UTCTimestamp, Error> {
+		let mut timespec = MaybeUninit::<libc::timespec>::uninit();
+
+		// SAFETY: `timespec` is a valid, suitably-aligned out-pointer for `clock_gettime`; we
+		// only read it below after checking the call succeeded.
+		let status = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, timespec.as_mut_ptr()) };
+		if status != 0 {
+			return Err(Error::for_system(Kind::GatewayError,
+				"clock_gettime(CLOCK_REALTIME, ..) returned a non-zero status".to_string()));
+		}
+
+		// SAFETY: `clock_gettime` returned success, so `timespec` was fully written.
+		let timespec = unsafe { timespec.assume_init() };
+		if timespec.tv_sec < 0 || timespec.tv_nsec < 0 {
+			return Err(Error::for_system(Kind::GatewayError,
+				"clock_gettime(CLOCK_REALTIME, ..) returned a timestamp before the Unix epoch".to_string()));
+		}
+
+		let nanos = (timespec.tv_sec as u128) * 1_000_000_000 + timespec.tv_nsec as u128;
+
+		UTCTimestamp::builder()
+			.use_ns(nanos)
+			.build()
+			.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+											format!("Failed to build the UTCTimestamp from clock_gettime: {}", e)))
+	}
+}
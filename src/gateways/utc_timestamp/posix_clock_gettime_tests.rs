@@ -0,0 +1,28 @@
+#![cfg(feature = "posix_clock_gettime")]
+
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use crate::gateways::utc_timestamp::posix_clock_gettime::PosixClockGettimeUTCTimeStampGW;
+
+use attestify_test_framework::is_ok;
+
+/// # Test: `clock_gettime_now_success`
+///
+/// ## Purpose
+/// - Verify `PosixClockGettimeUTCTimeStampGW::now()` returns a valid `UTCTimestamp` whose
+///   `as_sec()` reading is consistent with `as_nano()` and `as_milli()`, mirroring the
+///   bucket/floor checks used for `SystemTimeUTCTimeStampGW`.
+#[test]
+fn clock_gettime_now_success() {
+	let gateway = PosixClockGettimeUTCTimeStampGW::new();
+	let timestamp = is_ok!(gateway.now());
+
+	let nanos: u128 = timestamp.as_nano() as u128;
+	let millis: u128 = timestamp.as_milli() as u128;
+	let secs: u128 = timestamp.as_sec() as u128;
+
+	const NANOS_PER_MILLI: u128 = 1_000_000;
+	const MILLIS_PER_SEC: u128 = 1000;
+
+	assert!(nanos >= millis * NANOS_PER_MILLI);
+	assert!(millis >= secs * MILLIS_PER_SEC);
+}
@@ -0,0 +1,68 @@
+use attestify_kernel::values::datetime::utc_timestamp::UTCTimestamp;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+/// The normalized `(seconds, nanos)` split used by Protobuf/ROS2 `Timestamp` messages: `nanos`
+/// is always in `0..1_000_000_000`, with any carry propagated into `seconds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtoSplitTimestamp {
+	pub seconds: i64,
+	pub nanos: i32,
+}
+
+impl ProtoSplitTimestamp {
+	/// Build a split timestamp, normalizing `nanos` into `0..1_000_000_000` and propagating the
+	/// carry into `seconds`. Returns an `Error` instead of panicking if the carry would overflow
+	/// `i64::seconds`.
+	pub fn new(seconds: i64, nanos: i32) -> Result<Self, Error> {
+		let carry_seconds = nanos.div_euclid(1_000_000_000) as i64;
+		let normalized_nanos = nanos.rem_euclid(1_000_000_000);
+
+		let normalized_seconds = seconds.checked_add(carry_seconds)
+			.ok_or_else(|| Error::for_system(Kind::ProcessingFailure,
+				format!("Normalizing nanos {} into seconds {} overflowed i64", nanos, seconds)))?;
+
+		Ok(Self { seconds: normalized_seconds, nanos: normalized_nanos })
+	}
+}
+
+/// Convert `timestamp` to the normalized `(seconds, nanos)` split. Fails if the timestamp's
+/// nanosecond-since-epoch count doesn't fit in `i64` seconds.
+pub fn to_proto_split(timestamp: &UTCTimestamp) -> Result<ProtoSplitTimestamp, Error> {
+	let total_nanos = timestamp.as_nano();
+
+	let seconds = total_nanos / 1_000_000_000;
+	let nanos = total_nanos % 1_000_000_000;
+
+	let seconds = i64::try_from(seconds)
+		.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+			format!("UTCTimestamp seconds {} do not fit in i64: {}", seconds, e)))?;
+	let nanos = i32::try_from(nanos)
+		.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+			format!("UTCTimestamp sub-second nanos {} do not fit in i32: {}", nanos, e)))?;
+
+	Ok(ProtoSplitTimestamp { seconds, nanos })
+}
+
+/// Convert a normalized `(seconds, nanos)` split back to a `UTCTimestamp`. Fails if `split`
+/// denotes a negative instant (before the Unix epoch) or one whose total nanosecond count
+/// exceeds the builder's `u128` range.
+pub fn from_proto_split(split: ProtoSplitTimestamp) -> Result<UTCTimestamp, Error> {
+	if split.seconds < 0 || !(0..1_000_000_000).contains(&split.nanos) {
+		return Err(Error::for_system(Kind::ProcessingFailure,
+			format!("ProtoSplitTimestamp {{ seconds: {}, nanos: {} }} is not a normalized, non-negative instant",
+					split.seconds, split.nanos)));
+	}
+
+	let total_nanos = (split.seconds as u128).checked_mul(1_000_000_000)
+		.and_then(|whole_seconds_ns| whole_seconds_ns.checked_add(split.nanos as u128))
+		.ok_or_else(|| Error::for_system(Kind::ProcessingFailure,
+			format!("ProtoSplitTimestamp {{ seconds: {}, nanos: {} }} overflows the UTCTimestamp nanosecond range",
+					split.seconds, split.nanos)))?;
+
+	UTCTimestamp::builder()
+		.use_ns(total_nanos)
+		.build()
+		.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+										format!("Failed to build the UTCTimestamp from the proto split: {}", e)))
+}
@@ -0,0 +1,69 @@
+use crate::gateways::utc_timestamp::controllable::ControllableUTCTimeStampGW;
+use crate::gateways::utc_timestamp::protobuf_split::{from_proto_split, to_proto_split, ProtoSplitTimestamp};
+
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_test_framework::is_ok;
+
+/// # Test: `new_normalizes_out_of_range_nanos_and_carries_into_seconds`
+///
+/// ## Purpose
+/// - Verify `ProtoSplitTimestamp::new` normalizes a `nanos` value outside `0..1_000_000_000`
+///   into that range, propagating the carry into `seconds`.
+#[test]
+fn new_normalizes_out_of_range_nanos_and_carries_into_seconds() {
+	let split = ProtoSplitTimestamp::new(10, 1_500_000_000).expect("normalization should succeed");
+
+	assert_eq!(split.seconds, 11);
+	assert_eq!(split.nanos, 500_000_000);
+}
+
+/// # Test: `new_rejects_a_carry_that_overflows_i64_seconds`
+///
+/// ## Purpose
+/// - Verify `ProtoSplitTimestamp::new` returns an `Err` instead of panicking or wrapping when
+///   normalizing `nanos` would carry `seconds` past `i64::MAX`.
+#[test]
+fn new_rejects_a_carry_that_overflows_i64_seconds() {
+	let split = ProtoSplitTimestamp::new(i64::MAX, 1_000_000_000);
+
+	assert!(split.is_err());
+}
+
+/// # Test: `to_proto_split_round_trips_through_from_proto_split`
+///
+/// ## Purpose
+/// - Verify converting a `UTCTimestamp` to its proto split and back yields the same instant.
+#[test]
+fn to_proto_split_round_trips_through_from_proto_split() {
+	let gateway = ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_123_456_789);
+	let timestamp = is_ok!(gateway.now());
+
+	let split = to_proto_split(&timestamp).expect("conversion should succeed");
+	let round_tripped = from_proto_split(split).expect("conversion should succeed");
+
+	assert_eq!(round_tripped.as_nano(), timestamp.as_nano());
+}
+
+/// # Test: `from_proto_split_rejects_a_negative_instant`
+///
+/// ## Purpose
+/// - Verify `from_proto_split` returns an `Err` instead of panicking for a split denoting a
+///   time before the Unix epoch.
+#[test]
+fn from_proto_split_rejects_a_negative_instant() {
+	let split = ProtoSplitTimestamp { seconds: -1, nanos: 0 };
+
+	assert!(from_proto_split(split).is_err());
+}
+
+/// # Test: `from_proto_split_rejects_an_unnormalized_nanos_field`
+///
+/// ## Purpose
+/// - Verify `from_proto_split` rejects a split whose `nanos` field is outside
+///   `0..1_000_000_000` rather than silently wrapping it.
+#[test]
+fn from_proto_split_rejects_an_unnormalized_nanos_field() {
+	let split = ProtoSplitTimestamp { seconds: 10, nanos: 1_000_000_000 };
+
+	assert!(from_proto_split(split).is_err());
+}
@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use attestify_kernel::gateways::utc_timestamp::UTCTimestampGateway;
+use attestify_kernel::error::Error;
+use attestify_kernel::error::Kind;
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// The largest value the 12-bit `rand_a` counter can hold before it would overflow into the
+/// version bits.
+const MAX_COUNTER: u16 = 0x0FFF;
+
+struct CounterState {
+	last_millis: u64,
+	counter: u16,
+}
+
+/// Generates time-ordered, sortable UUIDv7 identifiers (RFC 9562) from any
+/// [`UTCTimestampGateway`]. It composes with [`ControllableUTCTimeStampGW`](
+/// crate::gateways::utc_timestamp::controllable::ControllableUTCTimeStampGW) for deterministic
+/// tests, since it depends only on the `UTCTimestampGateway` trait.
+///
+/// Within the same millisecond, instead of regenerating the `rand_a` bits, the generator
+/// increments an internal counter so that UUIDs minted in quick succession still sort strictly
+/// after one another. The counter resets whenever the millisecond advances.
+///
+/// The counter lives entirely in the 12-bit `rand_a` field, so `next_uuid` supports at most
+/// [`MAX_COUNTER`] + 1 generations (4096, fewer when the first UUID of the millisecond seeds the
+/// counter from a non-zero sub-millisecond fraction) within a single millisecond per instance; once that's
+/// exhausted it returns `Err` rather than wrapping or blocking, so callers minting UUIDs in a
+/// tight loop should rate-limit or accept the possibility of an `Err` until the clock ticks
+/// over.
+pub struct UuidV7Gateway<G: UTCTimestampGateway> {
+	clock: G,
+	state: Mutex<CounterState>,
+}
+
+impl<G: UTCTimestampGateway> UuidV7Gateway<G> {
+	pub fn new(clock: G) -> Self {
+		Self { clock, state: Mutex::new(CounterState { last_millis: 0, counter: 0 }) }
+	}
+
+	/// Generate the next UUIDv7, as 16 big-endian bytes.
+	pub fn next_uuid(&self) -> Result<[u8; 16], Error> {
+		let timestamp = self.clock.now()?;
+		let millis = timestamp.as_milli() as u64;
+
+		let mut state = self.state.lock()
+			.expect("UuidV7Gateway mutex poisoned");
+
+		let rand_a = if millis == state.last_millis {
+			if state.counter >= MAX_COUNTER {
+				return Err(Error::for_system(Kind::ProcessingFailure,
+					format!("UUIDv7 rand_a counter overflowed {} generations within the same millisecond", MAX_COUNTER)));
+			}
+			state.counter += 1;
+			state.counter
+		} else {
+			state.last_millis = millis;
+			let sub_milli_nanos = (timestamp.as_nano() % 1_000_000) as u128;
+			state.counter = ((sub_milli_nanos * 4096) / 1_000_000) as u16 & MAX_COUNTER;
+			state.counter
+		};
+
+		let mut rand_b = [0u8; 8];
+		OsRng.fill_bytes(&mut rand_b);
+
+		let mut uuid = [0u8; 16];
+		uuid[0] = (millis >> 40) as u8;
+		uuid[1] = (millis >> 32) as u8;
+		uuid[2] = (millis >> 24) as u8;
+		uuid[3] = (millis >> 16) as u8;
+		uuid[4] = (millis >> 8) as u8;
+		uuid[5] = millis as u8;
+
+		uuid[6] = 0b0111_0000 | ((rand_a >> 8) as u8 & 0x0F); // version 0111
+		uuid[7] = rand_a as u8;
+
+		uuid[8] = 0b1000_0000 | (rand_b[0] & 0x3F); // variant 10
+		uuid[9] = rand_b[1];
+		uuid[10] = rand_b[2];
+		uuid[11] = rand_b[3];
+		uuid[12] = rand_b[4];
+		uuid[13] = rand_b[5];
+		uuid[14] = rand_b[6];
+		uuid[15] = rand_b[7];
+
+		Ok(uuid)
+	}
+}
@@ -0,0 +1,77 @@
+use crate::gateways::utc_timestamp::controllable::ControllableUTCTimeStampGW;
+use crate::gateways::uuid_v7::time_ordered::UuidV7Gateway;
+
+/// # Test: `generated_uuid_has_the_v7_version_and_variant_bits_set`
+///
+/// ## Purpose
+/// - Verify the version nibble is `0111` and the variant bits are `10`, as required by RFC 9562
+///   for UUIDv7.
+#[test]
+fn generated_uuid_has_the_v7_version_and_variant_bits_set() {
+	let gateway = UuidV7Gateway::new(ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_123_456_789));
+
+	let uuid = gateway.next_uuid().expect("uuid generation should succeed");
+
+	assert_eq!(uuid[6] >> 4, 0b0111, "version nibble should be 0111");
+	assert_eq!(uuid[8] >> 6, 0b10, "variant bits should be 10");
+}
+
+/// # Test: `timestamp_field_matches_the_millisecond_count`
+///
+/// ## Purpose
+/// - Verify the first 48 bits of the UUID are the big-endian millisecond count from the clock.
+#[test]
+fn timestamp_field_matches_the_millisecond_count() {
+	let millis: u64 = 1_700_000_000_123;
+	let gateway = UuidV7Gateway::new(ControllableUTCTimeStampGW::fixed_ns((millis as u128) * 1_000_000));
+
+	let uuid = gateway.next_uuid().expect("uuid generation should succeed");
+
+	let mut encoded: u64 = 0;
+	for byte in &uuid[0..6] {
+		encoded = (encoded << 8) | *byte as u64;
+	}
+
+	assert_eq!(encoded, millis);
+}
+
+/// # Test: `same_millisecond_calls_sort_strictly_after_one_another`
+///
+/// ## Purpose
+/// - Verify that repeated calls within the same millisecond produce UUIDs whose `rand_a`
+///   counter strictly increases, so byte-wise comparison still sorts them in generation order.
+#[test]
+fn same_millisecond_calls_sort_strictly_after_one_another() {
+	let gateway = UuidV7Gateway::new(ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_000_000_000));
+
+	let first = gateway.next_uuid().expect("uuid generation should succeed");
+	let second = gateway.next_uuid().expect("uuid generation should succeed");
+	let third = gateway.next_uuid().expect("uuid generation should succeed");
+
+	assert!(first[6..8] < second[6..8]);
+	assert!(second[6..8] < third[6..8]);
+}
+
+/// # Test: `counter_overflow_within_a_millisecond_errors_instead_of_panicking_or_wrapping`
+///
+/// ## Purpose
+/// - Verify that once the 12-bit `rand_a` counter is exhausted within a single millisecond
+///   (more than 4095 generations), `next_uuid` returns `Err` instead of panicking or silently
+///   wrapping the counter back to 0, which would produce a non-monotonic, colliding UUID.
+#[test]
+fn counter_overflow_within_a_millisecond_errors_instead_of_panicking_or_wrapping() {
+	let gateway = UuidV7Gateway::new(ControllableUTCTimeStampGW::fixed_ns(1_700_000_000_000_000_000));
+
+	let mut last_success_rand_a = None;
+	for _ in 0..4096 {
+		match gateway.next_uuid() {
+			Ok(uuid) => last_success_rand_a = Some(u16::from_be_bytes([uuid[6] & 0x0F, uuid[7]])),
+			Err(_) => break,
+		}
+	}
+	assert!(last_success_rand_a.is_some(), "expected at least one successful generation before overflow");
+
+	for _ in 0..8 {
+		assert!(gateway.next_uuid().is_err(), "counter should stay exhausted until the millisecond advances");
+	}
+}